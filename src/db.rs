@@ -662,11 +662,12 @@ impl<T: ThreadMode> DBWithThreadMode<T> {
                 &mut cfhandles,
                 access_type,
             )?;
-            for handle in &cfhandles {
+            for (cf_desc, handle) in cfs_v.iter().zip(&cfhandles) {
                 if handle.is_null() {
-                    return Err(Error::new(
-                        "Received null column family handle from DB.".to_owned(),
-                    ));
+                    return Err(Error::new(format!(
+                        "Received null column family handle from DB for column family `{}`.",
+                        cf_desc.name
+                    )));
                 }
             }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::path::Path;
 use std::ptr::{null_mut, NonNull};
@@ -919,6 +920,35 @@ pub enum LogLevel {
     Header,
 }
 
+impl From<LogLevel> for c_int {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Debug => 0,
+            LogLevel::Info => 1,
+            LogLevel::Warn => 2,
+            LogLevel::Error => 3,
+            LogLevel::Fatal => 4,
+            LogLevel::Header => 5,
+        }
+    }
+}
+
+impl TryFrom<c_int> for LogLevel {
+    type Error = Error;
+
+    fn try_from(level: c_int) -> Result<Self, Error> {
+        match level {
+            0 => Ok(LogLevel::Debug),
+            1 => Ok(LogLevel::Info),
+            2 => Ok(LogLevel::Warn),
+            3 => Ok(LogLevel::Error),
+            4 => Ok(LogLevel::Fatal),
+            5 => Ok(LogLevel::Header),
+            _ => Err(Error::new(format!("Unknown log level: {level}"))),
+        }
+    }
+}
+
 impl Options {
     /// Constructs the DBOptions and ColumnFamilyDescriptors by loading the
     /// latest RocksDB options file stored in the specified rocksdb database.
@@ -1802,7 +1832,7 @@ impl Options {
     /// ```
     pub fn set_log_level(&mut self, level: LogLevel) {
         unsafe {
-            ffi::rocksdb_options_set_info_log_level(self.inner, level as c_int);
+            ffi::rocksdb_options_set_info_log_level(self.inner, level.into());
         }
     }
 
@@ -4579,8 +4609,12 @@ impl Drop for DBPath {
 
 #[cfg(test)]
 mod tests {
+    use std::convert::TryFrom;
+
+    use libc::c_int;
+
     use crate::db_options::WriteBufferManager;
-    use crate::{Cache, CompactionPri, MemtableFactory, Options};
+    use crate::{Cache, CompactionPri, LogLevel, MemtableFactory, Options};
 
     #[test]
     fn test_enable_statistics() {
@@ -4633,6 +4667,26 @@ mod tests {
         assert!(write_buffer_manager.enabled());
     }
 
+    #[test]
+    fn log_level_round_trip() {
+        // Values of the native `rocksdb::InfoLogLevel` enum.
+        let levels = [
+            (LogLevel::Debug, 0),
+            (LogLevel::Info, 1),
+            (LogLevel::Warn, 2),
+            (LogLevel::Error, 3),
+            (LogLevel::Fatal, 4),
+            (LogLevel::Header, 5),
+        ];
+        for (level, native) in levels {
+            assert_eq!(c_int::from(level), native);
+            assert_eq!(LogLevel::try_from(native).unwrap(), level);
+        }
+
+        assert!(LogLevel::try_from(-1).is_err());
+        assert!(LogLevel::try_from(6).is_err());
+    }
+
     #[test]
     fn compaction_pri() {
         let mut opts = Options::default();
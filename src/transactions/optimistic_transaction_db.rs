@@ -181,11 +181,12 @@ impl<T: ThreadMode> OptimisticTransactionDB<T> {
 
             db = Self::open_cf_raw(opts, &cpath, &cfs_v, &cfnames, &cfopts, &mut cfhandles)?;
 
-            for handle in &cfhandles {
+            for (cf_desc, handle) in cfs_v.iter().zip(&cfhandles) {
                 if handle.is_null() {
-                    return Err(Error::new(
-                        "Received null column family handle from DB.".to_owned(),
-                    ));
+                    return Err(Error::new(format!(
+                        "Received null column family handle from DB for column family `{}`.",
+                        cf_desc.name
+                    )));
                 }
             }
 